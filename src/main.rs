@@ -1,20 +1,67 @@
 use actix_web::{web, App, HttpResponse, HttpServer};
 use chrono::{DateTime, NaiveDateTime, Utc};
 use lazy_static::lazy_static;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
 use std::fs;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-#[derive(Debug, PartialEq, Deserialize)]
+use futures::stream::{self, StreamExt};
+
+use bdk::bitcoin::Address as BtcAddress;
+use bdk::electrum_client::{Client as ElectrumClient, ConfigBuilder, ElectrumApi};
+use chronik_client::ChronikClient;
+use qrcode::render::svg;
+use qrcode::QrCode;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Clone, Deserialize)]
 enum ApiType {
     Chainz,
     Blnscan,
+    Electrum { url: String },
+    Chronik { url: String },
+}
+
+/// How many recent transactions to keep and render per address.
+const RECENT_TX_LIMIT: usize = 10;
+
+/// Maximum number of in-flight upstream requests during a refresh cycle.
+const REFRESH_CONCURRENCY: usize = 8;
+
+/// Per-address request timeout so one slow explorer can't stall the cycle.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Fallback refresh interval when `coins.toml` does not set one.
+const DEFAULT_REFRESH_SECS: u64 = 60;
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone)]
+struct TxSummary {
+    txid: String,
+    /// Signed native-unit delta for this address; positive is incoming. `None`
+    /// when the backend's history doesn't carry a per-tx amount (e.g. Electrum).
+    delta: Option<f64>,
+    value: Option<f64>,
+    time: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone)]
+struct TokenBalance {
+    token_id: String,
+    ticker: String,
+    /// Token protocol, e.g. "SLP" or "ALP".
+    token_type: String,
+    amount: f64,
 }
 
 #[derive(Deserialize)]
 pub struct Coins {
     coins: Vec<Coin>,
+    /// How often the background refresher re-queries every address, in seconds.
+    #[serde(default)]
+    refresh_interval_secs: Option<u64>,
 }
 
 #[derive(Debug, PartialEq, Deserialize)]
@@ -22,9 +69,31 @@ struct Coin {
     name: String,
     ticker: String,
     api: ApiType,
+    #[serde(default)]
+    show_qr: bool,
+    #[serde(default)]
+    alerts: Option<AlertConfig>,
     addresses: Vec<Address>,
 }
 
+/// Per-coin notification triggers configured in `coins.toml`. Any combination of
+/// thresholds and sinks may be set; an empty section disables alerting.
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+struct AlertConfig {
+    /// Fire when an address has had no new block activity for this many seconds.
+    #[serde(default)]
+    inactivity_secs: Option<u64>,
+    /// Fire when an address' balance moves by at least this much between refreshes.
+    #[serde(default)]
+    balance_delta: Option<f32>,
+    /// POST the alert payload to this URL.
+    #[serde(default)]
+    webhook: Option<String>,
+    /// Emit the alert as a log line on stdout.
+    #[serde(default)]
+    log: bool,
+}
+
 #[derive(Debug, PartialEq, Deserialize)]
 struct Address {
     address: String,
@@ -32,13 +101,176 @@ struct Address {
     balance: Option<f32>,
     #[serde(default)]
     last_block_timestamp: Option<u64>,
+    #[serde(default)]
+    tokens: Vec<TokenBalance>,
+    #[serde(default)]
+    recent_txs: Vec<TxSummary>,
 }
 
 lazy_static! {
     static ref COINS: Mutex<Vec<Coin>> = Mutex::new(Vec::new());
+    // Watch-only Electrum connections, keyed by server URL. Each is opened once
+    // (connecting can be slow) and then reused for every refresh so that
+    // request handling never pays the connection cost.
+    static ref ELECTRUM: Mutex<HashMap<String, Arc<ElectrumClient>>> = Mutex::new(HashMap::new());
+    // Chronik clients, cached per indexer URL for the same reason as ELECTRUM:
+    // the connection is opened once and reused for every refresh.
+    static ref CHRONIK: Mutex<HashMap<String, Arc<ChronikClient>>> = Mutex::new(HashMap::new());
+    // Append-only (timestamp, balance) series per address, grown by at most one
+    // sample per refresh cycle and exposed via /history for charting.
+    static ref HISTORY: Mutex<HashMap<String, Vec<(u64, f32)>>> = Mutex::new(HashMap::new());
+    // Whether an inactivity alert is currently latched for an address, so the
+    // trigger fires once on the transition into inactivity rather than on every
+    // refresh cycle while the address stays stale.
+    static ref INACTIVITY_FIRED: Mutex<HashMap<String, bool>> = Mutex::new(HashMap::new());
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Record a balance sample for `address` and fire any configured triggers. The
+/// `(timestamp, balance)` series is appended whenever a balance is known, so
+/// `/history` can chart any address regardless of alerting; triggers are evaluated only when the coin has
+/// an `[alerts]` section. The previous sample is compared before the new one is
+/// appended so a balance-delta trigger sees the change across refreshes.
+async fn record_and_alert(alerts: Option<&AlertConfig>, address: &str, balance: Option<f32>, last_block_timestamp: Option<u64>) {
+    let now = now_secs();
+    let mut messages = Vec::new();
+
+    {
+        let mut history = HISTORY.lock().unwrap();
+        let series = history.entry(address.to_string()).or_default();
+        let previous = series.last().map(|(_, balance)| *balance);
+        if let Some(balance) = balance {
+            if let (Some(previous), Some(threshold)) =
+                (previous, alerts.and_then(|a| a.balance_delta))
+            {
+                let change = balance - previous;
+                if change.abs() >= threshold {
+                    messages.push(format!(
+                        "{}: balance changed by {} (now {})",
+                        address, change, balance
+                    ));
+                }
+            }
+            series.push((now, balance));
+        }
+    }
+
+    // Everything below this point is alert dispatch; skip it entirely when the
+    // coin configured no triggers.
+    let Some(alerts) = alerts else { return };
+
+    if let (Some(threshold), Some(last)) = (alerts.inactivity_secs, last_block_timestamp) {
+        let idle = now.saturating_sub(last);
+        let mut fired = INACTIVITY_FIRED.lock().unwrap();
+        let latched = fired.get(address).copied().unwrap_or(false);
+        if idle > threshold {
+            // Only alert on the edge into inactivity; keep it latched until the
+            // address sees new activity again so the sink isn't spammed.
+            if !latched {
+                messages.push(format!(
+                    "{}: no block activity for {} seconds",
+                    address, idle
+                ));
+                fired.insert(address.to_string(), true);
+            }
+        } else {
+            fired.insert(address.to_string(), false);
+        }
+    }
+
+    for message in messages {
+        if alerts.log {
+            println!("[alert] {}", message);
+        }
+        if let Some(webhook) = &alerts.webhook {
+            let _ = reqwest::Client::new()
+                .post(webhook)
+                .json(&json!({ "message": message }))
+                .send()
+                .await;
+        }
+    }
 }
 
-fn load_coins() -> Vec<Coin> {
+/// Query an Electrum server for the balance and most-recent activity of a single
+/// watch-only address. Balances come from the address' UTXO set; the latest
+/// activity timestamp is the block header time of its newest confirmed
+/// transaction. Electrum's client is blocking, so callers run this off the
+/// request executor via [`tokio::task::spawn_blocking`].
+///
+/// We keep a cached connection per server and re-derive the balance from the
+/// live UTXO set each cycle rather than maintaining a persisted BDK wallet that
+/// is synced once at startup: the background refresher already re-queries every
+/// backend on a fixed interval, so a cached `ElectrumApi` connection gives the
+/// same "sync on start, reuse the socket afterwards" behaviour the BDK path
+/// would, without the extra persisted-wallet state that no other backend keeps.
+fn query_electrum(
+    url: &str,
+    address: &str,
+) -> Result<(f32, Option<u64>, Vec<TxSummary>), Box<dyn std::error::Error + Send + Sync>> {
+    // Open (or reuse) the connection under the lock, then release it before any
+    // query round-trip. Once a server's connection exists the lock is held only
+    // long enough to clone the handle; the first connect to a server still
+    // happens under the lock and delays other Electrum queries until it ends.
+    let client = {
+        let mut connections = ELECTRUM.lock().unwrap();
+        if !connections.contains_key(url) {
+            // Give the socket a read timeout so a hung server unblocks the
+            // blocking worker thread itself. The outer `tokio::time::timeout`
+            // around the future would only drop the JoinHandle while the
+            // thread kept running and holding this connection; the read
+            // timeout bounds the actual round-trips.
+            let config = ConfigBuilder::new()
+                .timeout(Some(REQUEST_TIMEOUT.as_secs() as u8))
+                .build();
+            connections.insert(
+                url.to_string(),
+                Arc::new(ElectrumClient::from_config(url, config)?),
+            );
+        }
+        Arc::clone(connections.get(url).unwrap())
+    };
+
+    let script = BtcAddress::from_str(address)?.assume_checked().script_pubkey();
+
+    let unspent = client.script_list_unspent(&script)?;
+    let sats: u64 = unspent.iter().map(|utxo| utxo.value).sum();
+    let balance = sats as f32 / 100_000_000.0;
+
+    // Summarise the most recent confirmed transactions, newest first; the first
+    // entry also sets the last-activity time. Electrum's history only carries
+    // txid and height, so per-tx amounts aren't available without fetching each
+    // full transaction — delta/value are left `None` and the summary renders as
+    // a plain activity log of which blocks touched the address.
+    let mut history: Vec<_> = client
+        .script_get_history(&script)?
+        .into_iter()
+        .filter(|tx| tx.height > 0)
+        .collect();
+    history.sort_by(|a, b| b.height.cmp(&a.height));
+
+    let mut recent_txs = Vec::new();
+    for entry in history.into_iter().take(RECENT_TX_LIMIT) {
+        let header = client.block_header(entry.height as usize)?;
+        recent_txs.push(TxSummary {
+            txid: entry.tx_hash.to_string(),
+            delta: None,
+            value: None,
+            time: Some(header.time as u64),
+        });
+    }
+    let last_block_timestamp = recent_txs.first().and_then(|tx| tx.time);
+
+    Ok((balance, last_block_timestamp, recent_txs))
+}
+
+fn load_coins() -> (Vec<Coin>, Duration) {
     let toml_str = match fs::read_to_string("coins.toml") {
         Ok(content) => content,
         Err(e) => panic!("Error reading the config file: {:?}", e),
@@ -56,67 +288,409 @@ fn load_coins() -> Vec<Coin> {
                         address: addr.address.clone(),
                         balance: None,
                         last_block_timestamp: None,
+                        tokens: Vec::new(),
+                        recent_txs: Vec::new(),
                     })
                     .collect();
             }
-            coins.coins
+            let interval = Duration::from_secs(
+                coins.refresh_interval_secs.unwrap_or(DEFAULT_REFRESH_SECS),
+            );
+            (coins.coins, interval)
         }
         Err(e) => panic!("Error parsing TOML: {:?}", e),
     }
 }
 
-async fn update_coins_list() -> Result<(), Box<dyn std::error::Error>> {
-    let mut coins_list = COINS.lock().unwrap();
-    for coin in &mut *coins_list {
-        for addr in &mut coin.addresses {
-            match coin.api {
-                ApiType::Chainz => {
-                    let url = format!(
-                        "https://chainz.cryptoid.info/{}/api.dws?q=addressinfo&a={}",
-                        &coin.ticker.to_lowercase(),
-                        &addr.address
-                    );
-                    let resp = reqwest::get(url).await?;
-                    let res = resp.text().await?;
-
-                    let json_data: serde_json::Value = serde_json::from_str(&res)?;
-                    if let Some(balance) = json_data.get("balance").and_then(|b| b.as_f64()) {
-                        addr.balance = Some(balance as f32);
+/// Freshly-fetched state for a single address, merged back into `COINS` after a
+/// refresh cycle. Fields left empty preserve the previously-known values.
+#[derive(Default)]
+struct AddressUpdate {
+    balance: Option<f32>,
+    last_block_timestamp: Option<u64>,
+    tokens: Vec<TokenBalance>,
+    recent_txs: Vec<TxSummary>,
+}
+
+/// Query a single address against its coin's backend. Pure with respect to
+/// `COINS`: it performs only the upstream I/O and returns the result so callers
+/// can run many of these concurrently before taking the lock to apply them.
+async fn fetch_address(
+    api: &ApiType,
+    ticker: &str,
+    address: &str,
+) -> Result<AddressUpdate, Box<dyn std::error::Error>> {
+    let mut update = AddressUpdate::default();
+    match api {
+        ApiType::Chainz => {
+            let url = format!(
+                "https://chainz.cryptoid.info/{}/api.dws?q=addressinfo&a={}",
+                ticker.to_lowercase(),
+                address
+            );
+            let resp = reqwest::get(url).await?;
+            let res = resp.text().await?;
+
+            let json_data: serde_json::Value = serde_json::from_str(&res)?;
+            if let Some(balance) = json_data.get("balance").and_then(|b| b.as_f64()) {
+                update.balance = Some(balance as f32);
+            }
+
+            if let Some(last_timestamp) = json_data
+                .get("lastBlockTimestamp")
+                .and_then(|ts| ts.as_i64())
+            {
+                update.last_block_timestamp = Some(last_timestamp as u64);
+            }
+
+            // Chainz' `addressinfo` endpoint returns only the balance and last
+            // activity timestamp, no transaction list, so `recent_txs` stays
+            // empty for this backend.
+        }
+        ApiType::Electrum { url } => {
+            let url = url.clone();
+            let address = address.to_string();
+            let (balance, last_block_timestamp, recent_txs) =
+                tokio::task::spawn_blocking(move || query_electrum(&url, &address)).await??;
+            update.balance = Some(balance);
+            update.last_block_timestamp = last_block_timestamp;
+            update.recent_txs = recent_txs;
+        }
+        ApiType::Chronik { url } => {
+            let client = {
+                let mut clients = CHRONIK.lock().unwrap();
+                if !clients.contains_key(url) {
+                    clients.insert(url.clone(), Arc::new(ChronikClient::new(url.clone())?));
+                }
+                Arc::clone(clients.get(url).unwrap())
+            };
+
+            // Split the address' UTXO set into the native balance and a
+            // per-token tally; a UTXO carrying token data contributes to
+            // its token's amount rather than the native balance.
+            // A UTXO's `token` entry carries only the id, protocol and raw atom
+            // amount; the ticker and decimals live in the token's genesis info,
+            // so we tally atoms per token id here and resolve the display
+            // metadata from `client.token` once per token below.
+            let utxos = client.address(address).utxos().await?;
+            let mut sats: i64 = 0;
+            let mut atoms: HashMap<String, (String, i64)> = HashMap::new();
+            for utxo in utxos.utxos {
+                match utxo.token {
+                    Some(token) => {
+                        let entry = atoms
+                            .entry(token.token_id.clone())
+                            .or_insert_with(|| (token.token_type.clone(), 0));
+                        entry.1 += token.amount as i64;
                     }
+                    None => sats += utxo.value,
+                }
+            }
+            update.balance = Some(sats as f32 / 100_000_000.0);
+
+            let mut tokens = Vec::new();
+            for (token_id, (token_type, raw)) in atoms {
+                // Ticker and decimals come from genesis info; scale the raw atom
+                // total by decimals so the rendered balance is in whole-token
+                // units rather than off by 10^decimals.
+                let info = client.token(&token_id).await?;
+                let (ticker, decimals) = info
+                    .genesis_info
+                    .map(|g| (g.token_ticker, g.decimals))
+                    .unwrap_or_default();
+                tokens.push(TokenBalance {
+                    token_id,
+                    ticker,
+                    token_type,
+                    amount: raw as f64 / 10f64.powi(decimals as i32),
+                });
+            }
+            update.tokens = tokens;
+
+            // Summarise the most recent transactions; the newest one also
+            // sets the last-activity time.
+            let history = client.address(address).history(0, RECENT_TX_LIMIT).await?;
+            update.recent_txs = history
+                .txs
+                .iter()
+                .map(|tx| TxSummary {
+                    txid: tx.txid.clone(),
+                    delta: Some(tx.delta as f64 / 100_000_000.0),
+                    value: Some(tx.delta.unsigned_abs() as f64 / 100_000_000.0),
+                    time: tx.block.as_ref().map(|block| block.timestamp as u64),
+                })
+                .collect();
+            update.last_block_timestamp = update.recent_txs.iter().find_map(|tx| tx.time);
+        }
+        ApiType::Blnscan => {
+            let url = "https://blnexplorer.io/api/account/".to_owned() + address;
+            let resp = reqwest::get(url).await?;
+            let res = resp.text().await?;
+
+            let json_data: serde_json::Value = serde_json::from_str(&res)?;
+            if let Some(txns) = json_data.get("txns").and_then(|txns| txns.as_array()) {
+                update.recent_txs = txns
+                    .iter()
+                    .take(RECENT_TX_LIMIT)
+                    .map(|txn| {
+                        let delta = txn.get("value").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                        TxSummary {
+                            txid: txn
+                                .get("txHash")
+                                .and_then(|h| h.as_str())
+                                .unwrap_or_default()
+                                .to_string(),
+                            delta: Some(delta),
+                            value: Some(delta.abs()),
+                            time: txn.get("time").and_then(|t| {
+                                t.as_i64()
+                                    .or_else(|| t.as_str().and_then(|s| s.parse().ok()))
+                                    .map(|ts| ts as u64)
+                            }),
+                        }
+                    })
+                    .collect();
+                update.last_block_timestamp = update.recent_txs.iter().find_map(|tx| tx.time);
+            }
+        }
+    }
+    Ok(update)
+}
+
+async fn update_coins_list() -> Result<(), Box<dyn std::error::Error>> {
+    // Snapshot the work list so the lock isn't held across the network I/O. We
+    // capture each job's (ticker, address) identity rather than its positional
+    // index: a concurrent `add_address`/`remove_address` RPC can shift indices
+    // during the fetch window, and merging by index would then write a result
+    // onto the wrong address.
+    let jobs: Vec<(ApiType, String, String)> = {
+        let coins_list = COINS.lock().unwrap();
+        coins_list
+            .iter()
+            .flat_map(|coin| {
+                coin.addresses
+                    .iter()
+                    .map(move |addr| {
+                        (coin.api.clone(), coin.ticker.clone(), addr.address.clone())
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    };
+
+    // Issue the per-address requests concurrently with a bounded fan-out and a
+    // per-request timeout so one slow upstream can't hold up the rest.
+    let updates: Vec<(String, String, Option<AddressUpdate>)> = stream::iter(jobs)
+        .map(|(api, ticker, address)| async move {
+            let update =
+                match tokio::time::timeout(REQUEST_TIMEOUT, fetch_address(&api, &ticker, &address))
+                    .await
+                {
+                    Ok(Ok(update)) => Some(update),
+                    _ => None,
+                };
+            (ticker, address, update)
+        })
+        .buffer_unordered(REFRESH_CONCURRENCY)
+        .collect()
+        .await;
+
+    // Merge results back into the cache, then gather per-address samples to
+    // record (and any alert config) once the lock is released.
+    let mut pending_alerts: Vec<(Option<AlertConfig>, String, Option<f32>, Option<u64>)> =
+        Vec::new();
+    {
+        let mut coins_list = COINS.lock().unwrap();
+        for (ticker, address, update) in updates {
+            let Some(update) = update else { continue };
+            let Some(coin) = coins_list.iter_mut().find(|c| c.ticker == ticker) else {
+                continue;
+            };
+            let alerts = coin.alerts.clone();
+            let Some(addr) = coin.addresses.iter_mut().find(|a| a.address == address) else {
+                continue;
+            };
+            if update.balance.is_some() {
+                addr.balance = update.balance;
+            }
+            if update.last_block_timestamp.is_some() {
+                addr.last_block_timestamp = update.last_block_timestamp;
+            }
+            if !update.tokens.is_empty() {
+                addr.tokens = update.tokens;
+            }
+            if !update.recent_txs.is_empty() {
+                addr.recent_txs = update.recent_txs;
+            }
+            pending_alerts.push((
+                alerts,
+                addr.address.clone(),
+                addr.balance,
+                addr.last_block_timestamp,
+            ));
+        }
+    }
+
+    for (alerts, address, balance, last_block_timestamp) in pending_alerts {
+        record_and_alert(alerts.as_ref(), &address, balance, last_block_timestamp).await;
+    }
+
+    Ok(())
+}
+
+fn seconds_since_activity(last_timestamp: u64) -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    now.saturating_sub(last_timestamp)
+}
+
+#[derive(Serialize)]
+struct AddressView {
+    address: String,
+    balance: Option<f32>,
+    last_block_timestamp: Option<u64>,
+    seconds_since_activity: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct CoinView {
+    name: String,
+    ticker: String,
+    addresses: Vec<AddressView>,
+}
+
+fn coin_view(coin: &Coin) -> CoinView {
+    CoinView {
+        name: coin.name.clone(),
+        ticker: coin.ticker.clone(),
+        addresses: coin
+            .addresses
+            .iter()
+            .map(|addr| AddressView {
+                address: addr.address.clone(),
+                balance: addr.balance,
+                last_block_timestamp: addr.last_block_timestamp,
+                seconds_since_activity: addr.last_block_timestamp.map(seconds_since_activity),
+            })
+            .collect(),
+    }
+}
+
+/// REST view of the current `COINS` state for dashboards and scripts. Serves the
+/// last-known cache refreshed by the background task.
+async fn api_coins() -> HttpResponse {
+    let coins = COINS.lock().unwrap();
+    let view: Vec<CoinView> = coins.iter().map(coin_view).collect();
+    HttpResponse::Ok().json(view)
+}
+
+#[derive(Serialize)]
+struct HistoryPoint {
+    timestamp: u64,
+    balance: f32,
+}
+
+/// Return the recorded per-address balance series as JSON so it can be charted.
+async fn api_history() -> HttpResponse {
+    let history = HISTORY.lock().unwrap();
+    let out: HashMap<String, Vec<HistoryPoint>> = history
+        .iter()
+        .map(|(address, series)| {
+            let points = series
+                .iter()
+                .map(|(timestamp, balance)| HistoryPoint {
+                    timestamp: *timestamp,
+                    balance: *balance,
+                })
+                .collect();
+            (address.clone(), points)
+        })
+        .collect();
+    HttpResponse::Ok().json(out)
+}
+
+#[derive(Deserialize)]
+struct RpcRequest {
+    method: String,
+    #[serde(default)]
+    params: serde_json::Value,
+    #[serde(default)]
+    id: serde_json::Value,
+}
 
-                    if let Some(last_timestamp) = json_data
-                        .get("lastBlockTimestamp")
-                        .and_then(|ts| ts.as_i64())
-                    {
-                        addr.last_block_timestamp = Some(last_timestamp as u64);
+/// Minimal JSON-RPC 2.0 control surface sharing the same `App` as the HTML view.
+/// Supported methods: `get_balances`, `refresh`, `add_address`, `remove_address`.
+async fn api_rpc(req: web::Json<RpcRequest>) -> HttpResponse {
+    let result: Result<serde_json::Value, String> = match req.method.as_str() {
+        "get_balances" => {
+            let coins = COINS.lock().unwrap();
+            Ok(json!(coins.iter().map(coin_view).collect::<Vec<_>>()))
+        }
+        "refresh" => match update_coins_list().await {
+            Ok(()) => Ok(json!({ "refreshed": true })),
+            Err(e) => Err(e.to_string()),
+        },
+        "add_address" => {
+            let ticker = req.params.get("ticker").and_then(|t| t.as_str());
+            let address = req.params.get("address").and_then(|a| a.as_str());
+            match (ticker, address) {
+                (Some(ticker), Some(address)) => {
+                    let mut coins = COINS.lock().unwrap();
+                    match coins.iter_mut().find(|c| c.ticker == ticker) {
+                        Some(coin) => {
+                            coin.addresses.push(Address {
+                                address: address.to_string(),
+                                balance: None,
+                                last_block_timestamp: None,
+                                tokens: Vec::new(),
+                                recent_txs: Vec::new(),
+                            });
+                            Ok(json!({ "added": address }))
+                        }
+                        None => Err(format!("unknown ticker: {}", ticker)),
                     }
                 }
-                ApiType::Blnscan => {
-                    let url = "https://blnexplorer.io/api/account/".to_owned() + &addr.address;
-                    let resp = reqwest::get(url).await?;
-                    let res = resp.text().await?;
-
-                    let json_data: serde_json::Value = serde_json::from_str(&res)?;
-                    if let Some(txn) = json_data.get("txns").and_then(|txns| txns.get(0)) {
-                        if let Some(last_timestamp) = txn.get("time") {
-                            if let Some(timestamp) = last_timestamp
-                                .as_i64()
-                                .or_else(|| last_timestamp.as_str().and_then(|s| s.parse().ok()))
-                            {
-                                addr.last_block_timestamp = Some(timestamp as u64);
-                            }
+                _ => Err("expected params { ticker, address }".to_string()),
+            }
+        }
+        "remove_address" => {
+            let ticker = req.params.get("ticker").and_then(|t| t.as_str());
+            let address = req.params.get("address").and_then(|a| a.as_str());
+            match (ticker, address) {
+                (Some(ticker), Some(address)) => {
+                    let mut coins = COINS.lock().unwrap();
+                    match coins.iter_mut().find(|c| c.ticker == ticker) {
+                        Some(coin) => {
+                            let before = coin.addresses.len();
+                            coin.addresses.retain(|a| a.address != address);
+                            Ok(json!({ "removed": before - coin.addresses.len() }))
                         }
+                        None => Err(format!("unknown ticker: {}", ticker)),
                     }
                 }
+                _ => Err("expected params { ticker, address }".to_string()),
             }
         }
-    }
+        other => Err(format!("unknown method: {}", other)),
+    };
 
-    Ok(())
+    match result {
+        Ok(value) => HttpResponse::Ok().json(json!({
+            "jsonrpc": "2.0",
+            "result": value,
+            "id": req.id,
+        })),
+        Err(message) => HttpResponse::Ok().json(json!({
+            "jsonrpc": "2.0",
+            "error": { "code": -32603, "message": message },
+            "id": req.id,
+        })),
+    }
 }
 
 async fn respond() -> HttpResponse {
-    let _ = update_coins_list().await;
     let html_content = format!(
         include_str!("templates/index.html"),
         coins = format_coins(&COINS.lock().unwrap())
@@ -183,6 +757,19 @@ fn format_timestamp(timestamp: u64) -> String {
     }
 }
 
+/// Render a scannable QR code of `data` as an inline SVG. Returns an empty
+/// string if the payload can't be encoded so the template still renders.
+fn qr_svg(data: &str) -> String {
+    match QrCode::new(data.as_bytes()) {
+        Ok(code) => code
+            .render::<svg::Color>()
+            .min_dimensions(96, 96)
+            .quiet_zone(true)
+            .build(),
+        Err(_) => String::new(),
+    }
+}
+
 fn format_addresses(coin: &Coin, addresses: &[Address]) -> String {
     addresses
         .iter()
@@ -215,11 +802,64 @@ fn format_addresses(coin: &Coin, addresses: &[Address]) -> String {
                 } else {
                     "?".to_string()
                 },
+                if coin.show_qr {
+                    qr_svg(&address.address)
+                } else {
+                    String::new()
+                },
+                format_tokens(&address.tokens),
+                format_recent_txs(&address.recent_txs),
             )
         })
         .collect()
 }
 
+/// Render the most recent transactions as a collapsible list. Incoming and
+/// outgoing movement is distinguished by the sign of each summary's delta.
+fn format_recent_txs(txs: &[TxSummary]) -> String {
+    if txs.is_empty() {
+        return String::new();
+    }
+    let rows: String = txs
+        .iter()
+        .take(RECENT_TX_LIMIT)
+        .map(|tx| {
+            let time = tx
+                .time
+                .map_or("?".to_string(), format_timestamp);
+            // Only show a direction and amount when the backend reported a
+            // per-tx delta; otherwise render a plain txid/time activity row.
+            match (tx.delta, tx.value) {
+                (Some(delta), Some(value)) => {
+                    let direction = if delta < 0.0 { "out" } else { "in" };
+                    format!("<li>{} {} — {} at {}</li>", direction, value, tx.txid, time)
+                }
+                _ => format!("<li>{} at {}</li>", tx.txid, time),
+            }
+        })
+        .collect();
+    format!(
+        "<details class=\"recent-txs\"><summary>Recent transactions</summary><ul>{}</ul></details>",
+        rows
+    )
+}
+
+fn format_tokens(tokens: &[TokenBalance]) -> String {
+    if tokens.is_empty() {
+        return String::new();
+    }
+    let items: String = tokens
+        .iter()
+        .map(|token| {
+            format!(
+                "<li>{} {} ({})</li>",
+                token.amount, token.ticker, token.token_type
+            )
+        })
+        .collect();
+    format!("<ul class=\"tokens\">{}</ul>", items)
+}
+
 fn format_coins(coins: &[Coin]) -> String {
     coins
         .iter()
@@ -245,17 +885,167 @@ fn format_coins(coins: &[Coin]) -> String {
         .collect()
 }
 
+fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/").to(respond))
+        .service(web::resource("/api/coins").route(web::get().to(api_coins)))
+        .service(web::resource("/api/rpc").route(web::post().to(api_rpc)))
+        .service(web::resource("/history").route(web::get().to(api_history)));
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    let initial_coins = load_coins();
+    let (initial_coins, refresh_interval) = load_coins();
     *COINS.lock().unwrap() = initial_coins;
 
+    // Sync once so the first response isn't empty, then keep the cache fresh in
+    // the background so request handling never blocks on upstream I/O.
+    let _ = update_coins_list().await;
+    actix_web::rt::spawn(async move {
+        let mut ticker = tokio::time::interval(refresh_interval);
+        ticker.tick().await; // consume the immediate first tick
+        loop {
+            ticker.tick().await;
+            let _ = update_coins_list().await;
+        }
+    });
+
     HttpServer::new(move || {
         App::new()
-            .service(web::resource("/").to(respond))
+            .configure(configure)
             .service(actix_files::Files::new("/static", "./static"))
     })
     .bind("0.0.0.0:8080")?
     .run()
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test;
+
+    fn seed_coins() {
+        *COINS.lock().unwrap() = vec![Coin {
+            name: "Bitcoin".to_string(),
+            ticker: "BTC".to_string(),
+            api: ApiType::Blnscan,
+            show_qr: false,
+            alerts: None,
+            addresses: vec![Address {
+                address: "bc1qexampleaddress".to_string(),
+                balance: Some(1.5),
+                last_block_timestamp: Some(1_600_000_000),
+                tokens: Vec::new(),
+                recent_txs: Vec::new(),
+            }],
+        }];
+    }
+
+    #[actix_web::test]
+    async fn api_coins_returns_rest_shape() {
+        seed_coins();
+        let app = test::init_service(App::new().service(
+            web::resource("/api/coins").route(web::get().to(api_coins)),
+        ))
+        .await;
+
+        let req = test::TestRequest::get().uri("/api/coins").to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(body[0]["name"], "Bitcoin");
+        assert_eq!(body[0]["ticker"], "BTC");
+        assert_eq!(body[0]["addresses"][0]["address"], "bc1qexampleaddress");
+        assert_eq!(body[0]["addresses"][0]["balance"], 1.5);
+        assert_eq!(body[0]["addresses"][0]["last_block_timestamp"], 1_600_000_000u64);
+        assert!(body[0]["addresses"][0]["seconds_since_activity"].is_number());
+    }
+
+    #[actix_web::test]
+    async fn api_history_returns_recorded_series() {
+        seed_coins();
+        HISTORY
+            .lock()
+            .unwrap()
+            .insert("bc1qexampleaddress".to_string(), vec![(1_600_000_000, 1.5)]);
+        let app = test::init_service(App::new().service(
+            web::resource("/history").route(web::get().to(api_history)),
+        ))
+        .await;
+
+        let req = test::TestRequest::get().uri("/history").to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(body["bc1qexampleaddress"][0]["timestamp"], 1_600_000_000u64);
+        assert_eq!(body["bc1qexampleaddress"][0]["balance"], 1.5);
+    }
+
+    #[actix_web::test]
+    async fn api_rpc_get_balances_returns_coin_shape() {
+        seed_coins();
+        let app = test::init_service(App::new().service(
+            web::resource("/api/rpc").route(web::post().to(api_rpc)),
+        ))
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/rpc")
+            .set_json(json!({ "jsonrpc": "2.0", "method": "get_balances", "id": 1 }))
+            .to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+
+        assert_eq!(body["id"], 1);
+        assert_eq!(body["result"][0]["ticker"], "BTC");
+        assert_eq!(body["result"][0]["addresses"][0]["balance"], 1.5);
+    }
+
+    #[actix_web::test]
+    async fn api_rpc_add_and_remove_address() {
+        seed_coins();
+        let app = test::init_service(App::new().service(
+            web::resource("/api/rpc").route(web::post().to(api_rpc)),
+        ))
+        .await;
+
+        let add = test::TestRequest::post()
+            .uri("/api/rpc")
+            .set_json(json!({
+                "jsonrpc": "2.0",
+                "method": "add_address",
+                "params": { "ticker": "BTC", "address": "bc1qsecond" },
+                "id": 2
+            }))
+            .to_request();
+        let added: serde_json::Value = test::call_and_read_body_json(&app, add).await;
+        assert_eq!(added["result"]["added"], "bc1qsecond");
+        assert_eq!(COINS.lock().unwrap()[0].addresses.len(), 2);
+
+        let remove = test::TestRequest::post()
+            .uri("/api/rpc")
+            .set_json(json!({
+                "jsonrpc": "2.0",
+                "method": "remove_address",
+                "params": { "ticker": "BTC", "address": "bc1qsecond" },
+                "id": 3
+            }))
+            .to_request();
+        let removed: serde_json::Value = test::call_and_read_body_json(&app, remove).await;
+        assert_eq!(removed["result"]["removed"], 1);
+        assert_eq!(COINS.lock().unwrap()[0].addresses.len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn api_rpc_unknown_method_errors() {
+        seed_coins();
+        let app = test::init_service(App::new().service(
+            web::resource("/api/rpc").route(web::post().to(api_rpc)),
+        ))
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/rpc")
+            .set_json(json!({ "jsonrpc": "2.0", "method": "bogus", "id": 4 }))
+            .to_request();
+        let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["error"]["code"], -32603);
+    }
+}